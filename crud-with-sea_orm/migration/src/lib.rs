@@ -2,6 +2,10 @@ pub use sea_orm_migration::prelude::*;
 
 use crate::migrations::m20220101_000001_post;
 use crate::migrations::m20260121_020308_users;
+use crate::migrations::m20260122_000001_add_password_hash;
+use crate::migrations::m20260123_000001_create_roles_and_access;
+use crate::migrations::m20260124_000001_add_avatar_path;
+use crate::migrations::m20260125_000001_create_clicks;
 use crate::seeds::m20220120_000002_seed_posts;
 mod migrations;
 mod seeds;
@@ -14,6 +18,10 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20220101_000001_post::Migration),
             Box::new(m20260121_020308_users::Migration),
+            Box::new(m20260122_000001_add_password_hash::Migration),
+            Box::new(m20260123_000001_create_roles_and_access::Migration),
+            Box::new(m20260124_000001_add_avatar_path::Migration),
+            Box::new(m20260125_000001_create_clicks::Migration),
             Box::new(m20220120_000002_seed_posts::Migration),
         ]
     }
@@ -0,0 +1,39 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Clicks::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Clicks::Id))
+                    .col(integer(Clicks::UserId))
+                    .col(timestamp_with_time_zone(Clicks::CreatedAt))
+                    .col(string_null(Clicks::Ip))
+                    .col(string_null(Clicks::UserAgent))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Clicks::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Clicks {
+    Table,
+    Id,
+    UserId,
+    CreatedAt,
+    Ip,
+    UserAgent,
+}
@@ -0,0 +1,6 @@
+pub mod m20220101_000001_post;
+pub mod m20260121_020308_users;
+pub mod m20260122_000001_add_password_hash;
+pub mod m20260123_000001_create_roles_and_access;
+pub mod m20260124_000001_add_avatar_path;
+pub mod m20260125_000001_create_clicks;
@@ -0,0 +1,225 @@
+//! Authentication feature module: registration, login and a typed request extractor.
+//!
+//! This module adds a small auth subsystem on top of the `users` entity:
+//! - `POST /api/auth/register` hashes a password with Argon2 and creates a user
+//!   (plaintext passwords are never stored).
+//! - `POST /api/auth/login` verifies the password and returns a signed HS256 JWT.
+//! - [`AuthUser`] is an Actix `FromRequest` extractor that parses the
+//!   `Authorization: Bearer <token>` header, validates the token against
+//!   `AppState::jwt_secret`, and rejects with 401 otherwise — handlers take it
+//!   as an argument to require authentication.
+//!
+//! The JWT claims carry `sub` (the user id), `exp` and `iat`.
+
+use std::future::{Ready, ready};
+
+use actix_web::error::{ErrorInternalServerError, ErrorUnauthorized};
+use actix_web::{FromRequest, HttpRequest, HttpResponse, Result, dev::Payload, web};
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng};
+use argon2::Argon2;
+use chrono::{Duration, Utc};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use serde::{Deserialize, Serialize};
+
+use super::users::{User, user};
+use crate::AppState;
+use crate::error::AppError;
+
+/// Tokens stay valid for 24 hours before the client must log in again.
+const TOKEN_TTL_HOURS: i64 = 24;
+
+/// Mount the auth endpoints under `/auth`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/auth")
+            .route("/register", web::post().to(register))
+            .route("/login", web::post().to(login)),
+    );
+}
+
+//
+// DTOs
+//
+#[derive(Debug, Deserialize)]
+struct RegisterRequest {
+    name: String,
+    email: String,
+    password: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoginRequest {
+    email: String,
+    password: String,
+}
+
+#[derive(Debug, Serialize)]
+struct TokenResponse {
+    token: String,
+}
+
+/// Claims embedded in the signed JWT.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    /// Subject — the authenticated user's id.
+    pub sub: i32,
+    /// Expiry, as a Unix timestamp.
+    pub exp: usize,
+    /// Issued-at, as a Unix timestamp.
+    pub iat: usize,
+}
+
+//
+// Handlers
+//
+async fn register(
+    body: web::Json<RegisterRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, AppError> {
+    let db: &DatabaseConnection = &state.db;
+
+    // Reject a duplicate e-mail with 422 rather than letting the unique
+    // constraint surface as a raw 500 from the insert.
+    if User::find()
+        .filter(user::Column::Email.eq(&body.email))
+        .one(db)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::Validation(format!(
+            "email `{}` is already registered",
+            body.email
+        )));
+    }
+
+    let hash = hash_password(&body.password)?;
+
+    let active = user::ActiveModel {
+        name: Set(body.name.clone()),
+        email: Set(body.email.clone()),
+        password_hash: Set(Some(hash)),
+        created_at: Set(Some(Utc::now().fixed_offset())),
+        ..Default::default()
+    };
+
+    let created = active.insert(db).await?;
+
+    let token = issue_token(created.id, &state.jwt_secret)?;
+    Ok(HttpResponse::Created().json(TokenResponse { token }))
+}
+
+async fn login(
+    body: web::Json<LoginRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, AppError> {
+    let db: &DatabaseConnection = &state.db;
+
+    let found = User::find()
+        .filter(user::Column::Email.eq(&body.email))
+        .one(db)
+        .await?;
+
+    // Use the same error for "no such user" and "wrong password" so the endpoint
+    // doesn't reveal which e-mails are registered.
+    let user = found.ok_or(AppError::Unauthorized)?;
+    let stored = user
+        .password_hash
+        .as_deref()
+        .ok_or(AppError::Unauthorized)?;
+
+    verify_password(&body.password, stored)?;
+
+    let token = issue_token(user.id, &state.jwt_secret)?;
+    Ok(HttpResponse::Ok().json(TokenResponse { token }))
+}
+
+//
+// Password hashing helpers
+//
+fn hash_password(password: &str) -> Result<String, AppError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|h| h.to_string())
+        .map_err(|e| AppError::Internal(format!("hash error: {}", e)))
+}
+
+fn verify_password(password: &str, stored: &str) -> Result<(), AppError> {
+    let parsed = PasswordHash::new(stored)
+        .map_err(|e| AppError::Internal(format!("hash error: {}", e)))?;
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed)
+        .map_err(|_| AppError::Unauthorized)?;
+    Ok(())
+}
+
+//
+// JWT helpers
+//
+fn issue_token(user_id: i32, secret: &str) -> Result<String, AppError> {
+    let now = Utc::now();
+    let claims = Claims {
+        sub: user_id,
+        iat: now.timestamp() as usize,
+        exp: (now + Duration::hours(TOKEN_TTL_HOURS)).timestamp() as usize,
+    };
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .map_err(|e| AppError::Internal(format!("token error: {}", e)))
+}
+
+//
+// Extractor
+//
+/// The authenticated caller, resolved from a validated `Bearer` token.
+///
+/// Add it as a handler argument to require a valid JWT; extraction fails with
+/// 401 when the header is missing, malformed, or the token does not validate.
+pub struct AuthUser {
+    /// The user id carried in the token's `sub` claim.
+    pub user_id: i32,
+}
+
+impl FromRequest for AuthUser {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(authenticate(req))
+    }
+}
+
+/// Resolve the authenticated user from a request's `Bearer` token.
+///
+/// Shared by the [`AuthUser`] extractor and the `rbac` role guard so both apply
+/// exactly the same validation rules.
+pub fn authenticate(req: &HttpRequest) -> Result<AuthUser> {
+    let state = req
+        .app_data::<web::Data<AppState>>()
+        .ok_or_else(|| ErrorInternalServerError("missing app state"))?;
+
+    let header = req
+        .headers()
+        .get(actix_web::http::header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .ok_or_else(|| ErrorUnauthorized("missing authorization header"))?;
+
+    let token = header
+        .strip_prefix("Bearer ")
+        .ok_or_else(|| ErrorUnauthorized("expected a bearer token"))?;
+
+    let data = decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(state.jwt_secret.as_bytes()),
+        &Validation::default(),
+    )
+    .map_err(|_| ErrorUnauthorized("invalid token"))?;
+
+    Ok(AuthUser {
+        user_id: data.claims.sub,
+    })
+}
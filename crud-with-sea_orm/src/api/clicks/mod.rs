@@ -0,0 +1,67 @@
+//! Click/visit tracking: the `clicks` entity and a fire-and-forget recorder.
+//!
+//! Every hit on a tracked resource (currently `get_user`) records a row in the
+//! `clicks` table. Recording is intentionally non-blocking — it runs on a
+//! detached task so a failed or slow analytics insert can never fail or delay
+//! the request that triggered it. The per-resource time-series is served by the
+//! `users` module's `stats` endpoint.
+
+use actix_web::rt;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+
+/// Record a visit to `resource_id` without blocking the caller.
+///
+/// Spawns a detached task that inserts the access row; any error is swallowed
+/// so tracking is strictly best-effort.
+pub fn record_visit(
+    db: DatabaseConnection,
+    resource_id: i32,
+    ip: Option<String>,
+    user_agent: Option<String>,
+) {
+    rt::spawn(async move {
+        let row = click::ActiveModel {
+            user_id: Set(resource_id),
+            ip: Set(ip),
+            user_agent: Set(user_agent),
+            created_at: Set(Utc::now().fixed_offset()),
+            ..Default::default()
+        };
+        // Best-effort: analytics must never break the main request.
+        let _ = row.insert(&db).await;
+    });
+}
+
+//
+// SeaORM entity definition (clicks table)
+//
+pub mod click {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "clicks")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        /// The visited resource — currently a user id.
+        pub user_id: i32,
+        pub created_at: DateTimeWithTimeZone,
+        pub ip: Option<String>,
+        pub user_agent: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter)]
+    pub enum Relation {}
+
+    impl RelationTrait for Relation {
+        fn def(&self) -> RelationDef {
+            panic!("No Relations")
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub use click::Entity as Click;
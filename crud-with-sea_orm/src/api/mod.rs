@@ -3,20 +3,72 @@
 //! This module exposes a single `routes` function that your `main` can pass to
 //! `App::configure(...)`. It delegates to feature modules (e.g. `users`) which
 //! should each provide their own `pub fn routes(cfg: &mut web::ServiceConfig)`.
+//!
+//! It also assembles an [`ApiDoc`] — a single `utoipa::OpenApi` document that
+//! collects the `users` module's paths and schemas under the `users` tag —
+//! served as `/api/openapi.json` with an interactive Swagger UI at `/api/docs`.
+//! The `auth` endpoints are not in the document. New feature modules register
+//! by adding their paths/schemas to the lists below.
 
+use actix_files::Files;
 use actix_web::web;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
+pub mod auth;
+pub mod clicks;
+pub mod rbac;
 pub mod users;
 
-/// Mount all API routes under `/api`.
+/// The aggregated OpenAPI 3 document for the `/api` surface.
+///
+/// The `users` module contributes its `#[utoipa::path(...)]` handlers and its
+/// `ToSchema` DTOs here; the `tags` list gives it a stable grouping in the
+/// generated UI. The `auth` handlers are not annotated and are not listed.
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        users::list_users,
+        users::users_summary,
+        users::get_user,
+        users::create_user,
+        users::update_user,
+        users::delete_user,
+        users::upload_avatar,
+        users::user_stats,
+    ),
+    components(schemas(users::CreateUser, users::UpdateUser, users::UserResponse, users::UserSummary)),
+    tags((name = "users", description = "User CRUD endpoints"))
+)]
+pub struct ApiDoc;
+
+/// Mount all API routes under `/api`, serving uploads from `uploads_dir`.
+///
+/// `uploads_dir` is the single source of truth for where avatars live: pass the
+/// same value into `AppState::uploads_dir` so the static mount here and the
+/// write path in `upload_avatar` can never diverge.
 ///
 /// Example usage from `main.rs`:
-///     .configure(crud_with_sea_orm::api::routes)
-pub fn routes(cfg: &mut web::ServiceConfig) {
-    cfg.service(
-        web::scope("/api")
-            // Keep the API surface stable by grouping feature scopes under `/api`.
-            // Each feature module (e.g. `users`) should expose `routes`.
-            .configure(users::routes),
-    );
+///     let uploads_dir = std::env::var("UPLOADS_DIR").unwrap_or_else(|_| "./uploads".to_owned());
+///     App::new()
+///         .app_data(web::Data::new(AppState { uploads_dir: uploads_dir.clone(), .. }))
+///         .configure(crud_with_sea_orm::api::routes(uploads_dir))
+pub fn routes(uploads_dir: String) -> impl FnOnce(&mut web::ServiceConfig) {
+    move |cfg: &mut web::ServiceConfig| {
+        cfg.service(
+            web::scope("/api")
+                // Keep the API surface stable by grouping feature scopes under
+                // `/api`. Each feature module (e.g. `users`) exposes `routes`.
+                .configure(users::routes)
+                .configure(auth::routes)
+                // Serve uploaded avatar thumbnails as static files, from the
+                // same directory handlers write to (`AppState::uploads_dir`).
+                .service(Files::new("/uploads", uploads_dir))
+                // Serve the generated spec and an interactive Swagger UI so the
+                // API is self-documenting for clients. Paths are scope-relative
+                // so the `/api` prefix is applied once, landing on `/api/docs`
+                // and `/api/openapi.json`.
+                .service(SwaggerUi::new("/docs").url("/openapi.json", ApiDoc::openapi())),
+        );
+    }
 }
@@ -0,0 +1,138 @@
+//! Role-based access control: the `roles`/`access` entities and a role check.
+//!
+//! The data model follows the join-table design: a `roles` table names each
+//! role and an `access` table maps users to roles (`user_id`, `role_id`).
+//! [`user_has_role`] loads a caller's grants from the database; handlers that
+//! need to gate on a role call it inline, e.g. `users::delete_user` requiring
+//! `admin` before deleting.
+
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+
+use crate::error::AppError;
+
+/// Return whether `user_id` is granted the role named `role`.
+///
+/// Called by handlers that gate on a role inline (e.g. `users::delete_user`);
+/// DB failures surface as [`AppError::Db`].
+pub(crate) async fn user_has_role(
+    db: &DatabaseConnection,
+    user_id: i32,
+    role: &str,
+) -> Result<bool, AppError> {
+    let Some(role) = Role::find()
+        .filter(role::Column::Name.eq(role))
+        .one(db)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    let granted = Access::find()
+        .filter(access::Column::UserId.eq(user_id))
+        .filter(access::Column::RoleId.eq(role.id))
+        .one(db)
+        .await?
+        .is_some();
+
+    Ok(granted)
+}
+
+//
+// SeaORM entity definitions (roles, access)
+//
+pub mod role {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "roles")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter)]
+    pub enum Relation {}
+
+    impl RelationTrait for Relation {
+        fn def(&self) -> RelationDef {
+            panic!("No Relations")
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub mod access {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "access")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub user_id: i32,
+        pub role_id: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter)]
+    pub enum Relation {}
+
+    impl RelationTrait for Relation {
+        fn def(&self) -> RelationDef {
+            panic!("No Relations")
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub use access::Entity as Access;
+pub use role::Entity as Role;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DatabaseBackend, MockDatabase};
+
+    #[actix_web::test]
+    async fn grants_when_role_and_access_rows_exist() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![role::Model {
+                id: 1,
+                name: "admin".to_owned(),
+            }]])
+            .append_query_results([vec![access::Model {
+                id: 1,
+                user_id: 7,
+                role_id: 1,
+            }]])
+            .into_connection();
+
+        assert!(user_has_role(&db, 7, "admin").await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn denies_when_role_is_unknown() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<role::Model>::new()])
+            .into_connection();
+
+        assert!(!user_has_role(&db, 7, "admin").await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn denies_when_user_lacks_the_grant() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![role::Model {
+                id: 1,
+                name: "admin".to_owned(),
+            }]])
+            .append_query_results([Vec::<access::Model>::new()])
+            .into_connection();
+
+        assert!(!user_has_role(&db, 7, "admin").await.unwrap());
+    }
+}
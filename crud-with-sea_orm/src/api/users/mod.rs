@@ -10,12 +10,28 @@
 //! - Error handling is intentionally simple: SeaORM errors are converted to 500 Internal Server Error responses.
 //! - This is a compact, single-file example. For a larger project you may want to split entity/service/repo/handlers across files.
 
-use actix_web::{HttpResponse, Result, web};
+use actix_web::{HttpRequest, HttpResponse, Result, web};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, Set};
+use std::path::Path;
+
+use actix_multipart::Multipart;
+use chrono::{Duration, Utc};
+use futures_util::StreamExt;
+use image::imageops::FilterType;
+use sea_orm::sea_query::Expr;
+use sea_orm::{FromQueryResult, QueryOrder, QuerySelect};
+use sqids::Sqids;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, Set,
+};
 
 use crate::AppState;
+use crate::api::auth::AuthUser;
+use crate::api::clicks::{self, Click, click};
+use crate::api::rbac;
+use crate::error::AppError;
 
 /// Re-exported so `api::mod` can call `users::routes`.
 pub fn routes(cfg: &mut web::ServiceConfig) {
@@ -23,171 +39,508 @@ pub fn routes(cfg: &mut web::ServiceConfig) {
         web::scope("/users")
             .route("", web::get().to(list_users))
             .route("", web::post().to(create_user))
-            .route("/{id}", web::get().to(get_user))
-            .route("/{id}", web::put().to(update_user))
-            .route("/{id}", web::delete().to(delete_user)),
+            // Literal routes must precede the `/{id}` resource so they aren't
+            // swallowed as an id path segment.
+            .route("/summary", web::get().to(users_summary))
+            .route("/{id}/avatar", web::post().to(upload_avatar))
+            .route("/{id}/stats", web::get().to(user_stats))
+            // `get`/`put`/`delete` share the single `/{id}` resource — actix
+            // matches services by path, so a second resource on the same path
+            // would be dead code. `get`/`put` stay open (modulo the `AuthUser`
+            // requirement on `put`); `delete` enforces the `admin` role inline,
+            // since a per-route `.wrap` guard can't be expressed on a resource
+            // whose methods have different access rules.
+            .service(
+                web::resource("/{id}")
+                    .route(web::get().to(get_user))
+                    .route(web::put().to(update_user))
+                    .route(web::delete().to(delete_user)),
+            ),
     );
 }
 
 //
 // DTOs
 //
-#[derive(Debug, Deserialize)]
-struct CreateUser {
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateUser {
     name: String,
     email: String,
 }
 
-#[derive(Debug, Deserialize)]
-struct UpdateUser {
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateUser {
     name: Option<String>,
     email: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
-struct UserResponse {
-    id: i32,
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserResponse {
+    /// Opaque public id (a Sqids-encoded string), never the raw primary key.
+    id: String,
     name: String,
     email: String,
 }
 
+/// Query parameters accepted by `list_users`.
+#[derive(Debug, Deserialize)]
+struct ListQuery {
+    page: Option<u64>,
+    per_page: Option<u64>,
+    email_contains: Option<String>,
+    name_contains: Option<String>,
+}
+
+/// Paginated envelope wrapping a page of results with its cursor metadata.
+#[derive(Debug, Serialize)]
+struct Paginated<T> {
+    data: Vec<T>,
+    page: u64,
+    per_page: u64,
+    total_pages: u64,
+    total_items: u64,
+}
+
+/// Aggregate counts returned by `users_summary`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserSummary {
+    total_users: u64,
+    created_last_7_days: u64,
+}
+
+/// Raw row backing [`UserSummary`], produced by the single aggregate query.
+#[derive(Debug, Default, FromQueryResult)]
+struct SummaryRow {
+    total_users: i64,
+    created_last_7_days: i64,
+}
+
+/// Default page size when `per_page` is omitted.
+const DEFAULT_PER_PAGE: u64 = 20;
+/// Upper bound on `per_page` so a client can't request an unbounded page.
+const MAX_PER_PAGE: u64 = 100;
+
+/// Side length of the generated square avatar thumbnail, in pixels.
+const AVATAR_SIZE: u32 = 256;
+
+/// URL prefix under which the uploads directory is served (see `api::routes`).
+/// Joined with a stored `avatar_path` to form the publicly fetchable URL.
+const UPLOADS_URL_PREFIX: &str = "/api/uploads";
+
+/// Number of trailing days covered by the per-user stats time-series.
+const STATS_WINDOW_DAYS: i64 = 30;
+
+/// A single day's view count in the stats time-series.
+#[derive(Debug, Serialize, FromQueryResult)]
+struct DailyCount {
+    day: String,
+    count: i64,
+}
+
+/// Aggregate visit stats for a single user.
+#[derive(Debug, Serialize)]
+struct StatsResponse {
+    total_views: u64,
+    daily: Vec<DailyCount>,
+}
+
+//
+// Opaque id encoding
+//
+/// Encode an internal primary key into its opaque public string.
+fn encode_id(sqids: &Sqids, id: i32) -> String {
+    sqids.encode(&[id as u64]).unwrap_or_default()
+}
+
+/// Decode a public id back to the internal primary key.
+///
+/// Rejects with 400 when the string decodes to nothing or does not round-trip
+/// to its canonical encoding (which rejects tampered or non-minimal inputs).
+fn decode_id(sqids: &Sqids, encoded: &str) -> Result<i32, AppError> {
+    let numbers = sqids.decode(encoded);
+    let id = numbers
+        .first()
+        .copied()
+        .ok_or_else(|| AppError::BadRequest(format!("invalid id `{}`", encoded)))?;
+
+    if sqids.encode(&[id]).ok().as_deref() != Some(encoded) {
+        return Err(AppError::BadRequest(format!("invalid id `{}`", encoded)));
+    }
+
+    i32::try_from(id).map_err(|_| AppError::BadRequest(format!("invalid id `{}`", encoded)))
+}
+
 //
 // Handlers
 //
-async fn list_users(state: web::Data<AppState>) -> Result<HttpResponse> {
+#[utoipa::path(
+    get,
+    path = "/api/users",
+    tag = "users",
+    params(
+        ("page" = Option<u64>, Query, description = "1-based page number (default 1)"),
+        ("per_page" = Option<u64>, Query, description = "Page size (default 20, max 100)"),
+        ("email_contains" = Option<String>, Query, description = "Filter by e-mail substring"),
+        ("name_contains" = Option<String>, Query, description = "Filter by name substring")
+    ),
+    responses((status = 200, description = "A page of users in a `{ data, page, per_page, total_pages, total_items }` envelope"))
+)]
+pub async fn list_users(
+    query: web::Query<ListQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, AppError> {
     let db: &DatabaseConnection = &state.db;
-    let users = User::find()
-        .all(db)
-        .await
-        .map_err(|e| actix_web::error::ErrorInternalServerError(format!("db error: {}", e)))?;
 
-    // Map to response DTOs
-    let resp: Vec<UserResponse> = users
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE);
+
+    let mut finder = User::find();
+    if let Some(email) = &query.email_contains {
+        finder = finder.filter(user::Column::Email.contains(email));
+    }
+    if let Some(name) = &query.name_contains {
+        finder = finder.filter(user::Column::Name.contains(name));
+    }
+
+    let paginator = finder.paginate(db, per_page);
+    let total_items = paginator.num_items().await?;
+    let total_pages = paginator.num_pages().await?;
+    // `Paginator` pages are 0-based while the public API is 1-based.
+    let models = paginator.fetch_page(page - 1).await?;
+
+    let data: Vec<UserResponse> = models
         .into_iter()
         .map(|m| UserResponse {
-            id: m.id,
+            id: encode_id(&state.sqids, m.id),
             name: m.name,
             email: m.email,
         })
         .collect();
 
-    Ok(HttpResponse::Ok().json(resp))
+    Ok(HttpResponse::Ok().json(Paginated {
+        data,
+        page,
+        per_page,
+        total_pages,
+        total_items,
+    }))
 }
 
-async fn get_user(path: web::Path<i32>, state: web::Data<AppState>) -> Result<HttpResponse> {
-    let id = path.into_inner();
+#[utoipa::path(
+    get,
+    path = "/api/users/summary",
+    tag = "users",
+    responses((status = 200, description = "Aggregate user counts", body = UserSummary))
+)]
+pub async fn users_summary(state: web::Data<AppState>) -> Result<HttpResponse, AppError> {
     let db: &DatabaseConnection = &state.db;
 
-    match User::find_by_id(id)
+    let since = (Utc::now() - Duration::days(7)).fixed_offset();
+    // Both counts come from one aggregate pass; the conditional count uses a
+    // Postgres `FILTER` clause rather than a second query.
+    let row = User::find()
+        .select_only()
+        .column_as(user::Column::Id.count(), "total_users")
+        .column_as(
+            Expr::cust_with_values("COUNT(*) FILTER (WHERE created_at >= ?)", [since]),
+            "created_last_7_days",
+        )
+        .into_model::<SummaryRow>()
         .one(db)
-        .await
-        .map_err(|e| actix_web::error::ErrorInternalServerError(format!("db error: {}", e)))?
-    {
-        Some(user) => {
-            let resp = UserResponse {
-                id: user.id,
-                name: user.name,
-                email: user.email,
-            };
-            Ok(HttpResponse::Ok().json(resp))
-        }
-        None => Ok(HttpResponse::NotFound().body(format!("user {} not found", id))),
-    }
+        .await?
+        .unwrap_or_default();
+
+    Ok(HttpResponse::Ok().json(UserSummary {
+        total_users: row.total_users as u64,
+        created_last_7_days: row.created_last_7_days as u64,
+    }))
 }
 
-async fn create_user(
+#[utoipa::path(
+    get,
+    path = "/api/users/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "Opaque user id")),
+    responses(
+        (status = 200, description = "The requested user", body = UserResponse),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn get_user(
+    req: HttpRequest,
+    path: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, AppError> {
+    let encoded = path.into_inner();
+    let id = decode_id(&state.sqids, &encoded)?;
+    let db: &DatabaseConnection = &state.db;
+
+    let user = User::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("user {} not found", encoded)))?;
+
+    // Record the visit on a detached task so analytics never blocks the response.
+    let ip = req.peer_addr().map(|addr| addr.ip().to_string());
+    let user_agent = req
+        .headers()
+        .get(actix_web::http::header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_owned());
+    clicks::record_visit(state.db.clone(), id, ip, user_agent);
+
+    let resp = UserResponse {
+        id: encode_id(&state.sqids, user.id),
+        name: user.name,
+        email: user.email,
+    };
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users",
+    tag = "users",
+    request_body = CreateUser,
+    responses((status = 201, description = "User created", body = UserResponse))
+)]
+pub async fn create_user(
     body: web::Json<CreateUser>,
     state: web::Data<AppState>,
-) -> Result<HttpResponse> {
+) -> Result<HttpResponse, AppError> {
     let db: &DatabaseConnection = &state.db;
 
     let active = user::ActiveModel {
         // id is auto-increment primary key; leave as NotSet
         name: Set(body.name.clone()),
         email: Set(body.email.clone()),
-        // created_at default handled by DB or set to None
-        created_at: Set(None),
+        // Stamp creation time so `users_summary` can count recent sign-ups.
+        created_at: Set(Some(Utc::now().fixed_offset())),
         ..Default::default()
     };
 
-    let res = User::insert(active)
-        .exec(db)
-        .await
-        .map_err(|e| actix_web::error::ErrorInternalServerError(format!("db error: {}", e)))?;
+    let res = User::insert(active).exec(db).await?;
 
     // SeaORM's InsertResult may not return the full model; fetch it back.
     let created = User::find_by_id(res.last_insert_id)
         .one(db)
-        .await
-        .map_err(|e| actix_web::error::ErrorInternalServerError(format!("db error: {}", e)))?;
-
-    match created {
-        Some(m) => {
-            let resp = UserResponse {
-                id: m.id,
-                name: m.name,
-                email: m.email,
-            };
-            Ok(HttpResponse::Created().json(resp))
-        }
-        None => Ok(HttpResponse::InternalServerError().body("failed to fetch created user")),
-    }
+        .await?
+        .ok_or_else(|| AppError::Db(sea_orm::DbErr::RecordNotInserted))?;
+
+    let resp = UserResponse {
+        id: encode_id(&state.sqids, created.id),
+        name: created.name,
+        email: created.email,
+    };
+    Ok(HttpResponse::Created().json(resp))
 }
 
-async fn update_user(
-    path: web::Path<i32>,
+#[utoipa::path(
+    put,
+    path = "/api/users/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "Opaque user id")),
+    request_body = UpdateUser,
+    responses(
+        (status = 200, description = "User updated", body = UserResponse),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn update_user(
+    path: web::Path<String>,
     body: web::Json<UpdateUser>,
     state: web::Data<AppState>,
-) -> Result<HttpResponse> {
-    let id = path.into_inner();
+    _auth: AuthUser,
+) -> Result<HttpResponse, AppError> {
+    let encoded = path.into_inner();
+    let id = decode_id(&state.sqids, &encoded)?;
     let db: &DatabaseConnection = &state.db;
 
-    // Fetch existing
-    let existing = User::find_by_id(id)
+    let model = User::find_by_id(id)
         .one(db)
-        .await
-        .map_err(|e| actix_web::error::ErrorInternalServerError(format!("db error: {}", e)))?;
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("user {} not found", encoded)))?;
 
-    if let Some(model) = existing {
-        let mut active: user::ActiveModel = model.into();
+    let mut active: user::ActiveModel = model.into();
 
-        if let Some(name) = &body.name {
-            active.name = Set(name.clone());
-        }
-        if let Some(email) = &body.email {
-            active.email = Set(email.clone());
-        }
+    if let Some(name) = &body.name {
+        active.name = Set(name.clone());
+    }
+    if let Some(email) = &body.email {
+        active.email = Set(email.clone());
+    }
 
-        let updated = active
-            .update(db)
-            .await
-            .map_err(|e| actix_web::error::ErrorInternalServerError(format!("db error: {}", e)))?;
+    let updated = active.update(db).await?;
 
-        let resp = UserResponse {
-            id: updated.id,
-            name: updated.name,
-            email: updated.email,
-        };
-        Ok(HttpResponse::Ok().json(resp))
-    } else {
-        Ok(HttpResponse::NotFound().body(format!("user {} not found", id)))
-    }
+    let resp = UserResponse {
+        id: encode_id(&state.sqids, updated.id),
+        name: updated.name,
+        email: updated.email,
+    };
+    Ok(HttpResponse::Ok().json(resp))
 }
 
-async fn delete_user(path: web::Path<i32>, state: web::Data<AppState>) -> Result<HttpResponse> {
-    let id = path.into_inner();
+#[utoipa::path(
+    delete,
+    path = "/api/users/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "Opaque user id")),
+    responses(
+        (status = 200, description = "User deleted"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn delete_user(
+    path: web::Path<String>,
+    state: web::Data<AppState>,
+    auth: AuthUser,
+) -> Result<HttpResponse, AppError> {
     let db: &DatabaseConnection = &state.db;
 
-    let res = User::delete_by_id(id)
-        .exec(db)
-        .await
-        .map_err(|e| actix_web::error::ErrorInternalServerError(format!("db error: {}", e)))?;
+    // Deleting a user is reserved for admins. Enforced here rather than with a
+    // route guard because `get`/`put`/`delete` share one `/{id}` resource.
+    if !rbac::user_has_role(db, auth.user_id, "admin").await? {
+        return Err(AppError::Forbidden("role `admin` required".to_owned()));
+    }
+
+    let encoded = path.into_inner();
+    let id = decode_id(&state.sqids, &encoded)?;
+
+    let res = User::delete_by_id(id).exec(db).await?;
 
     if res.rows_affected > 0 {
-        Ok(HttpResponse::Ok().body(format!("deleted user {}", id)))
+        Ok(HttpResponse::Ok().json(serde_json::json!({ "deleted": encoded })))
     } else {
-        Ok(HttpResponse::NotFound().body(format!("user {} not found", id)))
+        Err(AppError::NotFound(format!("user {} not found", encoded)))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/{id}/avatar",
+    tag = "users",
+    params(("id" = String, Path, description = "Opaque user id")),
+    request_body(content = String, description = "multipart/form-data with a single image file field", content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Avatar stored; returns its public URL under `/api/uploads`"),
+        (status = 400, description = "Missing file or unsupported/invalid image"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn upload_avatar(
+    path: web::Path<String>,
+    mut payload: Multipart,
+    state: web::Data<AppState>,
+    _auth: AuthUser,
+) -> Result<HttpResponse, AppError> {
+    let encoded = path.into_inner();
+    let id = decode_id(&state.sqids, &encoded)?;
+    let db: &DatabaseConnection = &state.db;
+
+    let model = User::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("user {} not found", encoded)))?;
+
+    // Read the first file field into memory, validating its declared type.
+    let mut buffer: Option<Vec<u8>> = None;
+    while let Some(item) = payload.next().await {
+        let mut field = item.map_err(|e| AppError::BadRequest(format!("multipart error: {}", e)))?;
+
+        let content_type = field.content_type().map(|m| m.essence_str().to_owned());
+        match content_type.as_deref() {
+            Some("image/png" | "image/jpeg" | "image/webp") => {}
+            other => {
+                return Err(AppError::BadRequest(format!(
+                    "unsupported content type: {}",
+                    other.unwrap_or("<none>")
+                )));
+            }
+        }
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field.next().await {
+            let data = chunk.map_err(|e| AppError::BadRequest(format!("upload error: {}", e)))?;
+            bytes.extend_from_slice(&data);
+        }
+        buffer = Some(bytes);
+        break;
+    }
+
+    let bytes = buffer.ok_or_else(|| AppError::BadRequest("no file uploaded".to_owned()))?;
+
+    // Decode and center-crop to a square thumbnail; `resize_to_fill` preserves
+    // aspect ratio by cropping the overflowing dimension.
+    let image = image::load_from_memory(&bytes)
+        .map_err(|e| AppError::BadRequest(format!("invalid image: {}", e)))?;
+    let thumbnail = image.resize_to_fill(AVATAR_SIZE, AVATAR_SIZE, FilterType::Lanczos3);
+
+    let relative = format!("avatars/{}.png", id);
+    let full = Path::new(&state.uploads_dir).join(&relative);
+    if let Some(parent) = full.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| AppError::Internal(format!("could not create uploads dir: {}", e)))?;
     }
+    thumbnail
+        .save(&full)
+        .map_err(|e| AppError::Internal(format!("could not write avatar: {}", e)))?;
+
+    let mut active: user::ActiveModel = model.into();
+    active.avatar_path = Set(Some(relative.clone()));
+    active.update(db).await?;
+
+    // Return the served URL (mount prefix + stored path) so the client can
+    // fetch the avatar directly, rather than the bare filesystem-relative path.
+    let url = format!("{}/{}", UPLOADS_URL_PREFIX, relative);
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "avatar_path": url })))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/{id}/stats",
+    tag = "users",
+    params(("id" = String, Path, description = "Opaque user id")),
+    responses(
+        (status = 200, description = "Per-user visit stats: a total and a day-by-day series over the trailing window"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn user_stats(
+    path: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, AppError> {
+    let encoded = path.into_inner();
+    let id = decode_id(&state.sqids, &encoded)?;
+    let db: &DatabaseConnection = &state.db;
+
+    // Surface a 404 for unknown users rather than silently empty stats.
+    User::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("user {} not found", encoded)))?;
+
+    let total_views = Click::find()
+        .filter(click::Column::UserId.eq(id))
+        .count(db)
+        .await?;
+
+    let since = (Utc::now() - Duration::days(STATS_WINDOW_DAYS)).fixed_offset();
+    // One grouped query counting visits per calendar day over the window.
+    let daily = Click::find()
+        .select_only()
+        // Render the day as text so it deserializes into `DailyCount::day`;
+        // a bare `DATE(...)` yields a SQL `date` that won't `try_get` as String.
+        .column_as(Expr::cust("TO_CHAR(created_at, 'YYYY-MM-DD')"), "day")
+        .column_as(click::Column::Id.count(), "count")
+        .filter(click::Column::UserId.eq(id))
+        .filter(click::Column::CreatedAt.gte(since))
+        .group_by(Expr::cust("TO_CHAR(created_at, 'YYYY-MM-DD')"))
+        .order_by_asc(Expr::cust("TO_CHAR(created_at, 'YYYY-MM-DD')"))
+        .into_model::<DailyCount>()
+        .all(db)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(StatsResponse { total_views, daily }))
 }
 
 //
@@ -204,6 +557,14 @@ pub mod user {
         pub id: i32,
         pub name: String,
         pub email: String,
+        /// Argon2 password hash, set once the user registers through `api::auth`.
+        /// `None` for users created via the bare CRUD endpoint.
+        pub password_hash: Option<String>,
+        /// Path of the user's avatar thumbnail relative to the uploads
+        /// directory (e.g. `avatars/3.png`), set by the avatar upload endpoint.
+        /// It is served under `/api/uploads/`, so the public URL is that prefix
+        /// joined with this value.
+        pub avatar_path: Option<String>,
         /// Optional created_at field. The actual DB column type should match your DB (e.g. timestamptz).
         pub created_at: Option<sea_orm::prelude::DateTimeWithTimeZone>,
     }
@@ -222,3 +583,114 @@ pub mod user {
 
 /// Convenience re-export so top-level code can reference `users::User` if needed.
 pub use user::Entity as User;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::build_sqids;
+
+    use actix_web::http::{StatusCode, header};
+    use actix_web::{App, test, web};
+    use chrono::{Duration, Utc};
+    use jsonwebtoken::{EncodingKey, Header, encode};
+    use sea_orm::{DatabaseBackend, MockDatabase};
+
+    use crate::api::auth::Claims;
+    use crate::api::rbac::role;
+
+    #[test]
+    fn decode_id_round_trips_a_valid_encoding() {
+        let sqids = build_sqids();
+        let encoded = encode_id(&sqids, 42);
+        assert_eq!(decode_id(&sqids, &encoded).unwrap(), 42);
+    }
+
+    #[test]
+    fn decode_id_rejects_garbage() {
+        let sqids = build_sqids();
+        let err = decode_id(&sqids, "!!!!").unwrap_err();
+        assert!(matches!(err, AppError::BadRequest(_)));
+    }
+
+    #[test]
+    fn decode_id_rejects_non_canonical_encoding() {
+        let sqids = build_sqids();
+        // A string that decodes to some id but isn't that id's canonical
+        // encoding must be rejected rather than silently accepted.
+        let canonical = encode_id(&sqids, 7);
+        let tampered = format!("{}0", canonical);
+        if sqids.decode(&tampered).first().is_some() {
+            assert!(matches!(
+                decode_id(&sqids, &tampered),
+                Err(AppError::BadRequest(_))
+            ));
+        }
+    }
+
+    fn test_state(db: sea_orm::DatabaseConnection) -> AppState {
+        AppState {
+            db,
+            jwt_secret: "testsecret".to_owned(),
+            sqids: build_sqids(),
+            uploads_dir: "./uploads".to_owned(),
+        }
+    }
+
+    fn bearer_for(user_id: i32, secret: &str) -> String {
+        let now = Utc::now();
+        let claims = Claims {
+            sub: user_id,
+            iat: now.timestamp() as usize,
+            exp: (now + Duration::hours(1)).timestamp() as usize,
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .unwrap();
+        format!("Bearer {}", token)
+    }
+
+    #[actix_web::test]
+    async fn delete_is_rejected_without_auth() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(test_state(db)))
+                .configure(routes),
+        )
+        .await;
+
+        let id = encode_id(&build_sqids(), 1);
+        let req = test::TestRequest::delete()
+            .uri(&format!("/users/{}", id))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn delete_is_forbidden_for_non_admin() {
+        // The role lookup returns no `admin` role for the caller, so the guard
+        // must answer 403 — not 405, which is what the old dual-resource
+        // routing produced.
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<role::Model>::new()])
+            .into_connection();
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(test_state(db)))
+                .configure(routes),
+        )
+        .await;
+
+        let id = encode_id(&build_sqids(), 1);
+        let req = test::TestRequest::delete()
+            .uri(&format!("/users/{}", id))
+            .insert_header((header::AUTHORIZATION, bearer_for(99, "testsecret")))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
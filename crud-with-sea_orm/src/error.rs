@@ -0,0 +1,107 @@
+//! Uniform error type for the HTTP API.
+//!
+//! Every handler returns `Result<HttpResponse, AppError>`. [`AppError`]
+//! implements `actix_web::ResponseError`, so returning one — or propagating a
+//! `sea_orm::DbErr` with `?` — produces a consistent JSON body:
+//!
+//! ```json
+//! { "error": { "code": "forbidden", "message": "role `admin` required" } }
+//! ```
+
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use serde::Serialize;
+
+/// The crate-wide API error.
+#[derive(Debug)]
+pub enum AppError {
+    /// The requested resource does not exist (404).
+    NotFound(String),
+    /// The request body or parameters failed validation (422).
+    Validation(String),
+    /// An underlying database error (500).
+    Db(sea_orm::DbErr),
+    /// Authentication is missing or invalid (401).
+    Unauthorized,
+    /// The caller is authenticated but lacks permission (403).
+    Forbidden(String),
+    /// A malformed request that isn't a validation failure (400).
+    BadRequest(String),
+    /// An unexpected server-side failure, e.g. filesystem I/O (500).
+    Internal(String),
+}
+
+impl AppError {
+    /// Stable machine-readable code mirrored into the JSON body.
+    fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "not_found",
+            AppError::Validation(_) => "validation",
+            AppError::Db(_) => "db_error",
+            AppError::Unauthorized => "unauthorized",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::BadRequest(_) => "bad_request",
+            AppError::Internal(_) => "internal",
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::NotFound(msg) => write!(f, "{}", msg),
+            AppError::Validation(msg) => write!(f, "{}", msg),
+            AppError::Db(e) => write!(f, "db error: {}", e),
+            AppError::Unauthorized => write!(f, "unauthorized"),
+            AppError::Forbidden(msg) => write!(f, "{}", msg),
+            AppError::BadRequest(msg) => write!(f, "{}", msg),
+            AppError::Internal(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<sea_orm::DbErr> for AppError {
+    fn from(e: sea_orm::DbErr) -> Self {
+        AppError::Db(e)
+    }
+}
+
+/// The `{ code, message }` inner object.
+#[derive(Serialize)]
+struct ErrorBody {
+    code: &'static str,
+    message: String,
+}
+
+/// The `{ error: { .. } }` envelope wrapping every error response.
+#[derive(Serialize)]
+struct ErrorEnvelope {
+    error: ErrorBody,
+}
+
+impl ResponseError for AppError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Db(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::Unauthorized => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ErrorEnvelope {
+            error: ErrorBody {
+                code: self.code(),
+                message: self.to_string(),
+            },
+        })
+    }
+}
@@ -0,0 +1,38 @@
+//! Crate root for the `crud-with-sea_orm` example service.
+//!
+//! Exposes the `api` router tree and the shared [`AppState`] that every Actix
+//! handler receives via `web::Data<AppState>`.
+
+use sea_orm::DatabaseConnection;
+use sqids::Sqids;
+
+pub mod api;
+pub mod error;
+
+/// Shared application state injected into every handler.
+///
+/// Cloning is cheap: `DatabaseConnection` is internally reference-counted, so a
+/// clone per worker thread shares the same pool.
+#[derive(Clone)]
+pub struct AppState {
+    /// The SeaORM database connection pool.
+    pub db: DatabaseConnection,
+    /// Secret used to sign and validate HS256 JWTs issued by `api::auth`.
+    pub jwt_secret: String,
+    /// Encoder that maps internal auto-increment ids to opaque public strings.
+    pub sqids: Sqids,
+    /// Directory under which uploaded avatar thumbnails are written and served.
+    pub uploads_dir: String,
+}
+
+/// Build the shared [`Sqids`] encoder used to expose opaque public user ids.
+///
+/// The custom alphabet hides the default ordering and the minimum length pads
+/// short ids so `1` doesn't encode to a single character.
+pub fn build_sqids() -> Sqids {
+    Sqids::builder()
+        .alphabet("mnbvcxzlkjhgfdsapoiuytrewq9876543210".chars().collect())
+        .min_length(4)
+        .build()
+        .expect("valid sqids configuration")
+}